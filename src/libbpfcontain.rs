@@ -33,10 +33,13 @@ pub fn containerize(container_id: libc::c_ulong) -> Result<()> {
 }
 
 use bindings::capability_t;
+use bindings::default_policy_t;
 use bindings::file_permission_t;
+use bindings::mount_operation_t;
 use bindings::net_category_t;
 use bindings::net_operation_t;
 use bindings::policy_decision_t;
+use bindings::setuid_operation_t;
 
 bitflags! {
     /// Represents a policy decision from the BPF side.
@@ -52,6 +55,46 @@ bitflags! {
     }
 }
 
+/// Represents a container's default policy mode from the BPF side, i.e.
+/// what happens when no rule matches a given access.
+///
+/// Unlike the other `*_t`-backed types in this file, a container's default
+/// posture is mutually exclusive rather than a combinable set of flags, so
+/// this is a plain enum rather than `bitflags`.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum DefaultPolicy {
+    /// Unmatched access is denied. This is the default behavior.
+    Enforce = 0,
+    /// Unmatched access is allowed, but logged as a would-be denial.
+    Complain = 1,
+    /// Unmatched access is silently permitted; only explicit DENY rules bite.
+    DefaultAllow = 2,
+}
+
+impl Default for DefaultPolicy {
+    fn default() -> Self {
+        DefaultPolicy::Enforce
+    }
+}
+
+impl TryFrom<default_policy_t> for DefaultPolicy {
+    type Error = anyhow::Error;
+
+    fn try_from(value: default_policy_t) -> Result<Self> {
+        match value {
+            0 => Ok(DefaultPolicy::Enforce),
+            1 => Ok(DefaultPolicy::Complain),
+            2 => Ok(DefaultPolicy::DefaultAllow),
+            n => bail! {"Unknown default policy mode {}", n},
+        }
+    }
+}
+
 bitflags! {
     /// Represents the file permissions bitmask on the BPF side.
     ///
@@ -79,16 +122,64 @@ bitflags! {
 bitflags! {
     /// Represents the capabilities bitmask on the BPF side.
     ///
+    /// Bit values are aligned with the kernel's `CAP_*` capability numbers
+    /// (i.e. `1 << CAP_FOO`), so this covers the full Linux capability set
+    /// rather than a hand-picked subset. `capability_t` is widened to a
+    /// 64-bit type in [structs.h](src/include/structs.h) to fit bit 40
+    /// (`CHECKPOINT_RESTORE`).
+    ///
     /// # Warning
     ///
+    /// This is a breaking ABI change from the previous 32-bit, hand-picked
+    /// encoding: old policies compiled against `DAC_OVERRIDE = 0x08` will
+    /// silently decode as `FOWNER` under the new layout. There is no
+    /// wire-compatible migration; policies must be recompiled against this
+    /// version of structs.h/bindings.rs.
+    ///
     /// Keep this in sync with [structs.h](src/include/structs.h)
     #[derive(Default)]
     pub struct Capability :capability_t {
-        const NET_BIND_SERVICE = 0x00000001;
-        const NET_RAW          = 0x00000002;
-        const NET_BROADCAST    = 0x00000004;
-        const DAC_OVERRIDE     = 0x00000008;
-        const DAC_READ_SEARCH  = 0x00000010;
+        const CHOWN             = 0x0000000000000001;
+        const DAC_OVERRIDE      = 0x0000000000000002;
+        const DAC_READ_SEARCH   = 0x0000000000000004;
+        const FOWNER            = 0x0000000000000008;
+        const FSETID            = 0x0000000000000010;
+        const KILL              = 0x0000000000000020;
+        const SETGID            = 0x0000000000000040;
+        const SETUID            = 0x0000000000000080;
+        const SETPCAP           = 0x0000000000000100;
+        const LINUX_IMMUTABLE   = 0x0000000000000200;
+        const NET_BIND_SERVICE  = 0x0000000000000400;
+        const NET_BROADCAST     = 0x0000000000000800;
+        const NET_ADMIN         = 0x0000000000001000;
+        const NET_RAW           = 0x0000000000002000;
+        const IPC_LOCK          = 0x0000000000004000;
+        const IPC_OWNER         = 0x0000000000008000;
+        const SYS_MODULE        = 0x0000000000010000;
+        const SYS_RAWIO         = 0x0000000000020000;
+        const SYS_CHROOT        = 0x0000000000040000;
+        const SYS_PTRACE        = 0x0000000000080000;
+        const SYS_PACCT         = 0x0000000000100000;
+        const SYS_ADMIN         = 0x0000000000200000;
+        const SYS_BOOT          = 0x0000000000400000;
+        const SYS_NICE          = 0x0000000000800000;
+        const SYS_RESOURCE      = 0x0000000001000000;
+        const SYS_TIME          = 0x0000000002000000;
+        const SYS_TTY_CONFIG    = 0x0000000004000000;
+        const MKNOD             = 0x0000000008000000;
+        const LEASE             = 0x0000000010000000;
+        const AUDIT_WRITE       = 0x0000000020000000;
+        const AUDIT_CONTROL     = 0x0000000040000000;
+        const SETFCAP           = 0x0000000080000000;
+        const MAC_OVERRIDE      = 0x0000000100000000;
+        const MAC_ADMIN         = 0x0000000200000000;
+        const SYSLOG            = 0x0000000400000000;
+        const WAKE_ALARM        = 0x0000000800000000;
+        const BLOCK_SUSPEND     = 0x0000001000000000;
+        const AUDIT_READ        = 0x0000002000000000;
+        const PERFMON           = 0x0000004000000000;
+        const BPF               = 0x0000008000000000;
+        const CHECKPOINT_RESTORE = 0x0000010000000000;
     }
 }
 
@@ -124,6 +215,53 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Represents the mount operations bitmask on the BPF side.
+    ///
+    /// # Warning
+    ///
+    /// Keep this in sync with [structs.h](src/include/structs.h)
+    #[derive(Default)]
+    pub struct MountOperation :mount_operation_t {
+        const MAY_MOUNT   = 0x00000001;
+        const MAY_REMOUNT = 0x00000002;
+        const MAY_UMOUNT  = 0x00000004;
+        const MAY_MOVE    = 0x00000008;
+        const MAY_BIND    = 0x00000010;
+        const MAY_NOSUID  = 0x00000020;
+        const MAY_NODEV   = 0x00000040;
+        const MAY_NOEXEC  = 0x00000080;
+    }
+}
+
+bitflags! {
+    /// Represents the permitted UID/GID transitions bitmask on the BPF side,
+    /// enforced from the `task_fix_setuid` LSM hook.
+    ///
+    /// `task_fix_setuid` checks every credential field that can change uid
+    /// (`uid`, `euid`, `suid`, `fsuid`) independently, so `MAY_SETUID` and
+    /// `MAY_GAIN_PRIVS` are enforced against all of them -- not just the real
+    /// uid -- since `seteuid`/`setfsuid` can gain privileges without ever
+    /// touching the real uid.
+    ///
+    /// `MAY_SETGID`/`MAY_SETGROUPS` are currently unenforced: there is no
+    /// `task_fix_setgid`-equivalent LSM hook in the kernel, so gid
+    /// transitions can't be gated from `task_fix_setuid`. These flags are
+    /// kept for policy-file compatibility but have no effect until a gid
+    /// hook exists.
+    ///
+    /// # Warning
+    ///
+    /// Keep this in sync with [structs.h](src/include/structs.h)
+    #[derive(Default)]
+    pub struct SetuidOperation :setuid_operation_t {
+        const MAY_SETUID     = 0x00000001;
+        const MAY_SETGID     = 0x00000002;
+        const MAY_SETGROUPS  = 0x00000004;
+        const MAY_GAIN_PRIVS = 0x00000008;
+    }
+}
+
 /// Represents a container on the BPF side.
 ///
 /// # Warning
@@ -172,6 +310,36 @@ unsafe impl Plain for dev_policy_key {}
 pub use bindings::cap_policy_key;
 unsafe impl Plain for cap_policy_key {}
 
+/// Represents a single entry in the capability-audit ring buffer: a record
+/// of a capability check performed against a container, whether it was
+/// granted or denied, emitted for consumption in userspace.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::cap_audit_event;
+unsafe impl Plain for cap_audit_event {}
+
+/// Parses a raw ring buffer record from `cap_audit_events` into a
+/// [`cap_audit_event`]. Intended to be called from the ring buffer callback
+/// registered against that map, where `data` is the raw `&[u8]` handed to
+/// the callback by libbpf-rs.
+pub fn parse_cap_audit_event(data: &[u8]) -> Result<cap_audit_event> {
+    if data.len() < std::mem::size_of::<cap_audit_event>() {
+        bail!(
+            "short read parsing cap_audit_event: got {} bytes, need {}",
+            data.len(),
+            std::mem::size_of::<cap_audit_event>()
+        );
+    }
+    // SAFETY: cap_audit_event is a plain, all-integer C struct (see
+    // `unsafe impl Plain for cap_audit_event` above); zero is a valid
+    // bit pattern for it, and copy_from_bytes fully overwrites it next.
+    let mut event: cap_audit_event = unsafe { std::mem::zeroed() };
+    Plain::copy_from_bytes(&mut event, data).map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    Ok(event)
+}
+
 /// Represents a network policy key on the BPF side.
 ///
 /// # Warning
@@ -180,10 +348,72 @@ unsafe impl Plain for cap_policy_key {}
 pub use bindings::net_policy_key;
 unsafe impl Plain for net_policy_key {}
 
+/// Represents an address-scoped (CIDR/port) network policy key on the BPF
+/// side, matched against by longest-prefix.
+///
+/// Also carries a `table_id` field so a rule can be scoped to a specific
+/// routing table or VRF on multi-homed hosts, so that an address/port rule
+/// and a VRF rule compose instead of living on two keys that can't both
+/// apply to the same connection; `table_id == 0` means "any table",
+/// preserving backward compatibility with untagged rules.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::net_addr_policy_key;
+unsafe impl Plain for net_addr_policy_key {}
+
+/// Represents the spatial (container + family + prefix) part of an
+/// address-scoped network policy key on the BPF side, used to look up the
+/// bucket of [`net_addr_policy_key`] rules sharing that prefix.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::net_addr_prefix_key;
+unsafe impl Plain for net_addr_prefix_key {}
+
+/// Represents the bounded list of address-scoped network policy rules that
+/// share one [`net_addr_prefix_key`] on the BPF side, the value type of the
+/// `net_addr_policy` map.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::net_addr_policy_rules;
+unsafe impl Plain for net_addr_policy_rules {}
+
+/// Represents a mount policy key on the BPF side.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::mount_policy_key;
+unsafe impl Plain for mount_policy_key {}
+
+/// Represents a setuid/setgid privilege-transition policy key on the BPF
+/// side, keyed by container ID plus a target uid (or range).
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::setuid_policy_key;
+unsafe impl Plain for setuid_policy_key {}
+
+/// Represents a container's bounded list of setuid/setgid privilege-
+/// transition policy rules on the BPF side, the value type of the
+/// `setuid_policy` map.
+///
+/// # Warning
+///
+/// Keep this in sync with [structs.h](src/include/structs.h)
+pub use bindings::setuid_policy_rules;
+unsafe impl Plain for setuid_policy_rules {}
+
 /// Represents a per-inode key on the BPF side.
 ///
 /// # Warning
 ///
 /// Keep this in sync with [structs.h](src/include/structs.h)
 pub use bindings::inode_key;
-unsafe impl Plain for inode_key {}
\ No newline at end of file
+unsafe impl Plain for inode_key {}